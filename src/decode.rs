@@ -0,0 +1,67 @@
+use core::fmt;
+#[cfg(feature = "std")]
+use crate::engine::Engine;
+#[cfg(feature = "std")]
+use std::error;
+
+/// Errors that can occur while decoding. Available without `std`, since `Engine::internal_decode`
+/// needs it regardless of whether a caller ever reaches for the `Vec`-returning convenience
+/// functions below.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DecodeError {
+    /// An invalid byte was found in the input. The offset and offending byte are provided.
+    InvalidByte(usize, u8),
+    /// The length of the input is invalid.
+    InvalidLength,
+    /// The last non-padding input symbol's encoded 6 bits have nonzero bits that will be
+    /// discarded. This is indicative of corrupted or truncated Base64.
+    InvalidLastSymbol(usize, u8),
+    /// A quad ended in more `=` padding symbols (three or four) than a valid quad can have.
+    /// Missing padding shows up as `InvalidLength` instead.
+    InvalidPadding,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DecodeError::InvalidByte(index, byte) => {
+                write!(f, "Invalid byte {}, offset {}.", byte, index)
+            }
+            DecodeError::InvalidLength => write!(f, "Encoded text cannot have a 6-bit remainder."),
+            DecodeError::InvalidLastSymbol(index, byte) => {
+                write!(f, "Invalid last symbol {}, offset {}.", byte, index)
+            }
+            DecodeError::InvalidPadding => write!(f, "Invalid padding"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for DecodeError {
+    fn cause(&self) -> Option<&dyn error::Error> {
+        None
+    }
+}
+
+/// Decode input into a new `Vec`, using the given `Engine`.
+#[cfg(feature = "std")]
+pub fn decode_engine<E: Engine>(input: &[u8], engine: &E) -> Result<Vec<u8>, DecodeError> {
+    let mut buf = Vec::new();
+    decode_engine_vec(input, &mut buf, engine)?;
+    Ok(buf)
+}
+
+/// Decode input into the supplied `Vec`, appending the decoded bytes, using the given `Engine`.
+#[cfg(feature = "std")]
+pub fn decode_engine_vec<E: Engine>(
+    input: &[u8],
+    buffer: &mut Vec<u8>,
+    engine: &E,
+) -> Result<(), DecodeError> {
+    let starting_len = buffer.len();
+    // worst case: every input byte decodes to data (no padding)
+    buffer.resize(starting_len + input.len() / 4 * 3 + 3, 0);
+    let decoded_len = engine.internal_decode(input, &mut buffer[starting_len..])?;
+    buffer.truncate(starting_len + decoded_len);
+    Ok(())
+}
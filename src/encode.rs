@@ -0,0 +1,24 @@
+use crate::engine::Engine;
+
+/// Encode input into a new `String`, using the given `Engine`.
+pub fn encode_engine<E: Engine>(input: &[u8], engine: &E) -> String {
+    let mut buf = String::new();
+    encode_engine_string(input, &mut buf, engine);
+    buf
+}
+
+/// Encode input into the supplied `String`, appending the encoded text, using the given `Engine`.
+pub fn encode_engine_string<E: Engine>(input: &[u8], output_buf: &mut String, engine: &E) {
+    // SAFETY: the engine only ever writes valid utf8 (base64 alphabet symbols) into the buffer.
+    let output_bytes = unsafe { output_buf.as_mut_vec() };
+    let starting_len = output_bytes.len();
+    output_bytes.resize(starting_len + input.len().div_ceil(3) * 4, 0);
+
+    let mut input_chunks = input.chunks(3);
+    let mut out_offset = starting_len;
+    for chunk in &mut input_chunks {
+        out_offset += engine.internal_encode(chunk, &mut output_bytes[out_offset..]);
+    }
+
+    output_bytes.truncate(out_offset);
+}
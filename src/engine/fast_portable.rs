@@ -0,0 +1,161 @@
+//! A general-purpose `Engine` implementation that works correctly (if not maximally fast) on
+//! any platform, using a plain lookup table rather than SIMD.
+
+use crate::engine::{Config, Engine};
+use crate::DecodeError;
+
+const STANDARD_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+const URL_SAFE_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+const PAD_BYTE: u8 = b'=';
+const INVALID_VALUE: u8 = 255;
+
+/// A base64 alphabet: which 64 (distinct, ASCII) symbols are used to represent the 64 possible
+/// 6-bit values.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Alphabet {
+    pub(crate) symbols: [u8; 64],
+}
+
+impl Alphabet {
+    /// Create an `Alphabet` from 64 distinct ASCII byte symbols.
+    pub const fn from_str_unchecked(symbols: &[u8; 64]) -> Self {
+        Alphabet { symbols: *symbols }
+    }
+
+    /// The standard alphabet ([RFC 4648 ยง4](https://datatracker.ietf.org/doc/html/rfc4648#section-4)).
+    pub const STANDARD: Alphabet = Alphabet::from_str_unchecked(STANDARD_ALPHABET);
+
+    /// The URL-safe alphabet ([RFC 4648 ยง5](https://datatracker.ietf.org/doc/html/rfc4648#section-5)).
+    pub const URL_SAFE: Alphabet = Alphabet::from_str_unchecked(URL_SAFE_ALPHABET);
+
+    fn decode_table(&self) -> [u8; 256] {
+        let mut table = [INVALID_VALUE; 256];
+        for (value, &symbol) in self.symbols.iter().enumerate() {
+            table[symbol as usize] = value as u8;
+        }
+        table
+    }
+}
+
+/// A portable, table-lookup based `Engine`. Not the fastest possible implementation, but it's
+/// correct everywhere and doesn't depend on any particular CPU's SIMD instructions.
+#[derive(Clone, Copy, Debug)]
+pub struct FastPortable {
+    alphabet: Alphabet,
+    config: Config,
+}
+
+impl FastPortable {
+    /// Create a `FastPortable` engine using the provided alphabet and config.
+    pub const fn from(alphabet: &Alphabet, config: Config) -> Self {
+        FastPortable {
+            alphabet: *alphabet,
+            config,
+        }
+    }
+
+    pub(crate) const fn from_standard_alphabet(config: Config) -> Self {
+        FastPortable {
+            alphabet: Alphabet::STANDARD,
+            config,
+        }
+    }
+}
+
+impl Engine for FastPortable {
+    fn config(&self) -> &Config {
+        &self.config
+    }
+
+    fn internal_encode(&self, input: &[u8], output: &mut [u8]) -> usize {
+        let symbols = &self.alphabet.symbols;
+        let b0 = input[0];
+        let b1 = *input.get(1).unwrap_or(&0);
+        let b2 = *input.get(2).unwrap_or(&0);
+
+        output[0] = symbols[(b0 >> 2) as usize];
+        output[1] = symbols[((b0 << 4 | b1 >> 4) & 0x3f) as usize];
+
+        match input.len() {
+            1 => {
+                output[2] = PAD_BYTE;
+                output[3] = PAD_BYTE;
+            }
+            2 => {
+                output[2] = symbols[((b1 << 2) & 0x3f) as usize];
+                output[3] = PAD_BYTE;
+            }
+            _ => {
+                output[2] = symbols[((b1 << 2 | b2 >> 6) & 0x3f) as usize];
+                output[3] = symbols[(b2 & 0x3f) as usize];
+            }
+        }
+
+        4
+    }
+
+    fn internal_decode(&self, input: &[u8], output: &mut [u8]) -> Result<usize, DecodeError> {
+        if !input.len().is_multiple_of(4) {
+            return Err(DecodeError::InvalidLength);
+        }
+
+        let decode_table = self.alphabet.decode_table();
+        let mut out_len = 0;
+
+        for (chunk_index, quad) in input.chunks(4).enumerate() {
+            let base_offset = chunk_index * 4;
+            let pad_count = quad.iter().rev().take_while(|&&b| b == PAD_BYTE).count();
+            if pad_count > 0 && chunk_index != input.len() / 4 - 1 {
+                // padding is only legal in the final quad
+                return Err(DecodeError::InvalidByte(base_offset + (4 - pad_count), PAD_BYTE));
+            }
+
+            let mut sextets = [0u8; 4];
+            for (i, &symbol) in quad.iter().enumerate().take(4 - pad_count) {
+                let value = decode_table[symbol as usize];
+                if value == INVALID_VALUE {
+                    return Err(DecodeError::InvalidByte(base_offset + i, symbol));
+                }
+                sextets[i] = value;
+            }
+
+            let decoded_bytes = match pad_count {
+                0 => 3,
+                1 => 2,
+                2 => 1,
+                _ => return Err(DecodeError::InvalidPadding),
+            };
+
+            if decoded_bytes < 3 {
+                let last = sextets[decoded_bytes];
+                // The last symbol of a truncated group only contributes its high bits to the
+                // final output byte; e.g. for a 1-byte group (2 symbols) `out[0]` only consumes
+                // the top 2 bits of `sextets[1]`, so the low 4 bits must be zero.
+                let discarded_bits_mask = (1u8 << (2 * (3 - decoded_bytes))) - 1;
+                if !self.config.decode_allow_trailing_bits && last & discarded_bits_mask != 0 {
+                    return Err(DecodeError::InvalidLastSymbol(
+                        base_offset + decoded_bytes,
+                        quad[decoded_bytes],
+                    ));
+                }
+            }
+
+            let out = &mut output[out_len..out_len + decoded_bytes];
+            out[0] = (sextets[0] << 2) | (sextets[1] >> 4);
+            if decoded_bytes > 1 {
+                out[1] = (sextets[1] << 4) | (sextets[2] >> 2);
+            }
+            if decoded_bytes > 2 {
+                out[2] = (sextets[2] << 6) | sextets[3];
+            }
+
+            out_len += decoded_bytes;
+        }
+
+        Ok(out_len)
+    }
+}
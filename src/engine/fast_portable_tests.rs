@@ -0,0 +1,16 @@
+use crate::engine::Engine;
+use crate::engine::DEFAULT_ENGINE;
+
+// Regression test: `internal_decode` used to compute `discarded_bits_mask` with the 1-byte and
+// 2-byte tail cases swapped, so a perfectly valid padded 1- or 2-byte tail (e.g. "MDE=" / "01")
+// was rejected with `InvalidLastSymbol`.
+#[test]
+fn decodes_padded_one_and_two_byte_tails() {
+    let tests: &[(&[u8], &[u8])] = &[(&b"MA=="[..], &b"0"[..]), (&b"MDE="[..], &b"01"[..])][..];
+
+    for (base64data, expected) in tests.iter() {
+        let mut out = [0u8; 3];
+        let decoded_len = DEFAULT_ENGINE.internal_decode(base64data, &mut out).unwrap();
+        assert_eq!(*expected, &out[..decoded_len]);
+    }
+}
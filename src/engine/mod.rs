@@ -0,0 +1,78 @@
+//! Alternative engines for encoding and decoding base64.
+//!
+//! Most users won't need to touch this module and can instead use the `DEFAULT_ENGINE` via the
+//! top-level `encode`/`decode` functions, but it's here for callers that need a non-standard
+//! alphabet or padding behavior.
+
+pub mod fast_portable;
+#[cfg(all(test, feature = "std"))]
+mod fast_portable_tests;
+
+use crate::DecodeError;
+
+/// An `Engine` knows how to encode and decode base64 in terms of 3-byte <-> 4-symbol chunks.
+pub trait Engine: Send + Sync {
+    /// The config in use by this engine.
+    fn config(&self) -> &Config;
+
+    /// Encode up to 3 bytes of `input` into `output`, returning the number of symbols written
+    /// (always a multiple of 4, including any padding).
+    fn internal_encode(&self, input: &[u8], output: &mut [u8]) -> usize;
+
+    /// Decode `input` (one or more complete 4-symbol quads, with padding only allowed on the
+    /// final quad) into `output`, returning the number of bytes written.
+    fn internal_decode(&self, input: &[u8], output: &mut [u8]) -> Result<usize, DecodeError>;
+}
+
+/// Behavior knobs shared by every `Engine` implementation.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Config {
+    encode_padding: bool,
+    decode_allow_trailing_bits: bool,
+}
+
+impl Config {
+    /// Create a new `Config` with standard (padded, strict) behavior.
+    pub const fn new() -> Self {
+        Config {
+            encode_padding: true,
+            decode_allow_trailing_bits: false,
+        }
+    }
+
+    /// Whether encoded output is treated as padded. `FastPortable::internal_encode` always emits
+    /// `=` padding whatever this is set to; the flag is read by `DecoderReader`, which refuses
+    /// `SeekFrom::End` when it is `false`.
+    pub const fn with_encode_padding(self, encode_padding: bool) -> Self {
+        Config {
+            encode_padding,
+            ..self
+        }
+    }
+
+    /// Whether to allow nonzero trailing bits in the last symbol when decoding.
+    pub const fn with_decode_allow_trailing_bits(self, decode_allow_trailing_bits: bool) -> Self {
+        Config {
+            decode_allow_trailing_bits,
+            ..self
+        }
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn encode_padding(&self) -> bool {
+        self.encode_padding
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config::new()
+    }
+}
+
+pub(crate) const DEFAULT_ENGINE_CONFIG: Config = Config::new();
+
+/// The engine used by the crate-level `encode`/`decode` convenience functions: standard
+/// alphabet, padded, strict.
+pub static DEFAULT_ENGINE: fast_portable::FastPortable =
+    fast_portable::FastPortable::from_standard_alphabet(DEFAULT_ENGINE_CONFIG);
@@ -0,0 +1,46 @@
+//! A minimal, `no_std`-friendly stand-in for `std::io::Read`, used internally so
+//! [`DecoderReader`](crate::read::DecoderReader) can run without pulling in `std`.
+//!
+//! Every `std::io::Read` implementer gets this for free via the blanket impl below whenever the
+//! `std` feature is enabled (the default), so callers building with `std` never need to touch
+//! this trait directly.
+
+/// A source of bytes, playing the same role as `std::io::Read` without requiring `std`.
+pub trait Read {
+    /// The error a failed read produces.
+    type Error;
+
+    /// Pull some bytes from this source into `buf`, returning how many were read (`0` meaning
+    /// EOF), exactly like `std::io::Read::read`.
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error>;
+}
+
+#[cfg(feature = "std")]
+impl<R: std::io::Read> Read for R {
+    type Error = std::io::Error;
+
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        std::io::Read::read(self, buf)
+    }
+}
+
+/// Either a [`DecodeError`](crate::DecodeError) from malformed base64, or an error from the
+/// underlying byte source. Exposed as the error type of a no-`std` [`Read`] so there's no need
+/// for a `std::io::Error` to carry the `DecodeError` payload.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReadError<E> {
+    /// The underlying byte source failed.
+    Read(E),
+    /// The base64 itself was malformed.
+    Decode(crate::DecodeError),
+}
+
+#[cfg(feature = "std")]
+impl From<ReadError<std::io::Error>> for std::io::Error {
+    fn from(e: ReadError<std::io::Error>) -> Self {
+        match e {
+            ReadError::Read(e) => e,
+            ReadError::Decode(e) => std::io::Error::new(std::io::ErrorKind::InvalidData, e),
+        }
+    }
+}
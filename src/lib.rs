@@ -0,0 +1,39 @@
+//! # base64
+//!
+//! Encode and decode base64 as bytes or utf8 strings, with a pluggable [`engine::Engine`] so
+//! callers can trade off speed, alphabet, and padding behavior.
+//!
+//! Builds without `std` (disable default features) get [`read::DecoderReader`] over any
+//! [`io::Read`] byte source, but not the `Vec`/`String`-returning convenience functions or the
+//! `write` module, which need an allocator.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod decode;
+#[cfg(feature = "std")]
+mod encode;
+pub mod engine;
+pub mod io;
+pub mod read;
+#[cfg(all(feature = "std", test))]
+mod tests;
+#[cfg(feature = "std")]
+pub mod write;
+
+pub use crate::decode::DecodeError;
+#[cfg(feature = "std")]
+pub use crate::decode::{decode_engine, decode_engine_vec};
+#[cfg(feature = "std")]
+pub use crate::encode::{encode_engine, encode_engine_string};
+pub use crate::engine::DEFAULT_ENGINE;
+
+#[cfg(feature = "std")]
+/// Decode input using the default engine (standard alphabet, padded, strict).
+pub fn decode<T: AsRef<[u8]>>(input: T) -> Result<std::vec::Vec<u8>, DecodeError> {
+    decode_engine(input.as_ref(), &DEFAULT_ENGINE)
+}
+
+#[cfg(feature = "std")]
+/// Encode input using the default engine (standard alphabet, padded, strict).
+pub fn encode<T: AsRef<[u8]>>(input: T) -> std::string::String {
+    encode_engine(input.as_ref(), &DEFAULT_ENGINE)
+}
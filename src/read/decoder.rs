@@ -0,0 +1,395 @@
+#[cfg(feature = "std")]
+use std::io::{Seek, SeekFrom};
+
+use crate::engine::Engine;
+use crate::io::{Read, ReadError};
+use crate::DecodeError;
+
+const PAD_BYTE: u8 = b'=';
+
+// Big enough to amortize the cost of a `read` call on the delegate, small enough that a
+// `DecoderReader` doesn't take up an unreasonable amount of stack space.
+pub(crate) const BUF_SIZE: usize = 1024;
+
+// The most decoded bytes a full `BUF_SIZE` of encoded input can produce.
+const DECODED_BUF_SIZE: usize = BUF_SIZE / 4 * 3;
+
+/// A `Read` implementation that decodes base64 data read from a wrapped delegate reader.
+///
+/// Builds with the `std` feature (the default) get `std::io::Read`/`std::io::Seek`; without it,
+/// `DecoderReader` still works over any [`crate::io::Read`] byte source, reporting errors as
+/// [`ReadError`] instead.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "std")]
+/// # fn main() {
+/// use std::io::Read;
+/// use base64::read::DecoderReader;
+/// use base64::engine::DEFAULT_ENGINE;
+///
+/// let mut decoder = DecoderReader::from(&b"aGVsbG8gd29ybGQ="[..], &DEFAULT_ENGINE);
+/// let mut result = String::new();
+/// decoder.read_to_string(&mut result).unwrap();
+/// assert_eq!("hello world", result);
+/// # }
+/// # #[cfg(not(feature = "std"))]
+/// # fn main() {}
+/// ```
+pub struct DecoderReader<'e, E: Engine, R: Read> {
+    engine: &'e E,
+    delegate: R,
+
+    // Undecoded bytes read from the delegate. This only ever holds a partial quad (0-3 bytes)
+    // between calls: everything that forms a complete quad is decoded immediately.
+    b64_buffer: [u8; BUF_SIZE],
+    b64_len: usize,
+
+    // Decoded bytes waiting to be handed out via `read`.
+    decoded_buffer: [u8; DECODED_BUF_SIZE],
+    decoded_len: usize,
+    decoded_offset: usize,
+
+    // How many encoded bytes have been consumed so far, so that decode errors can be reported
+    // at their true offset into the overall stream rather than just the current chunk.
+    b64_consumed: usize,
+
+    at_eof: bool,
+
+    // Our current position in the *decoded* byte space, used by `Seek::seek(SeekFrom::Current)`
+    // and to know how many leading bytes of a freshly decoded quad to discard after seeking.
+    decoded_pos: u64,
+
+    // See `with_strict_mode`.
+    strict: bool,
+
+    // Strict mode only: the decoded bytes of the most recently decoded quad, which we can't yet
+    // be sure is genuinely the stream's final quad, so they're withheld from `decoded_buffer`
+    // until the next `fill_decoded_buffer` call either confirms EOF (in which case they move to
+    // `released`) or decodes more data (in which case the held bytes were not final after all,
+    // and also move to `released`, just before the new data).
+    held: [u8; 3],
+    held_len: usize,
+
+    // Strict mode only: held-back bytes that have now been confirmed safe to hand out, served
+    // ahead of `decoded_buffer` by `read_decoded`.
+    released: [u8; 3],
+    released_len: usize,
+    released_offset: usize,
+}
+
+impl<'e, E: Engine, R: Read> DecoderReader<'e, E, R> {
+    /// Create a new decoding reader that reads base64 data from `delegate` and decodes it with
+    /// `engine`.
+    pub fn from(delegate: R, engine: &'e E) -> Self {
+        DecoderReader {
+            engine,
+            delegate,
+            b64_buffer: [0; BUF_SIZE],
+            b64_len: 0,
+            decoded_buffer: [0; DECODED_BUF_SIZE],
+            decoded_len: 0,
+            decoded_offset: 0,
+            b64_consumed: 0,
+            at_eof: false,
+            decoded_pos: 0,
+            strict: false,
+            held: [0; 3],
+            held_len: 0,
+            released: [0; 3],
+            released_len: 0,
+            released_offset: 0,
+        }
+    }
+
+    /// Enable strict mode: the decoded bytes of the base64 stream's final group are only ever
+    /// handed out once EOF has actually been reached and that group's padding has been
+    /// validated.
+    ///
+    /// Without this, a caller doing repeated `read`/`read_exact` calls can receive decoded
+    /// bytes from the last group and only later learn (on a subsequent `read`) that the group
+    /// was malformed - e.g. bad padding, or trailing junk after what looked like a complete
+    /// stream - leaving them with partially-consumed output from input that should have
+    /// errored outright. Strict mode buffers up to the last 3 decoded bytes internally so that
+    /// never happens, at the cost of needing one extra `read` of the delegate to confirm EOF
+    /// before the tail of the output is released.
+    pub fn with_strict_mode(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    fn released_remaining(&self) -> &[u8] {
+        &self.released[self.released_offset..self.released_len]
+    }
+
+    fn decoded_remaining(&self) -> &[u8] {
+        &self.decoded_buffer[self.decoded_offset..self.decoded_len]
+    }
+
+    // Pull more base64 out of the delegate and decode as many complete quads as it yields,
+    // leaving 0-3 leftover encoded bytes in `b64_buffer` for the next call to pick up.
+    fn fill_decoded_buffer(&mut self) -> Result<(), ReadError<R::Error>> {
+        debug_assert_eq!(self.decoded_offset, self.decoded_len);
+        debug_assert_eq!(self.released_offset, self.released_len);
+        self.decoded_offset = 0;
+        self.decoded_len = 0;
+        self.released_offset = 0;
+        self.released_len = 0;
+
+        while self.decoded_len == 0 && self.released_len == 0 && !self.at_eof {
+            let read = self
+                .delegate
+                .read(&mut self.b64_buffer[self.b64_len..])
+                .map_err(ReadError::Read)?;
+            if read == 0 {
+                self.at_eof = true;
+                if !self.b64_len.is_multiple_of(4) {
+                    return Err(ReadError::Decode(DecodeError::InvalidLength));
+                }
+                // EOF confirms any held-back group really was the stream's last, valid group.
+                self.release_held();
+                continue;
+            }
+            self.b64_len += read;
+
+            let quad_bytes = self.b64_len / 4 * 4;
+            if quad_bytes == 0 {
+                continue;
+            }
+
+            let raw_decoded_len = self
+                .engine
+                .internal_decode(&self.b64_buffer[..quad_bytes], &mut self.decoded_buffer)
+                .map_err(|e| ReadError::Decode(offset_error(e, self.b64_consumed)))?;
+
+            if self.strict {
+                // More data decoded successfully, so whatever we were holding back from the
+                // previous batch wasn't actually the stream's final group after all.
+                self.release_held();
+
+                // Hold back this batch's final quad until the *next* call either confirms EOF
+                // or decodes something after it.
+                let last_quad = &self.b64_buffer[quad_bytes - 4..quad_bytes];
+                let pad_count = last_quad.iter().rev().take_while(|&&b| b == PAD_BYTE).count();
+                let held_len = 3 - pad_count;
+
+                self.decoded_len = raw_decoded_len - held_len;
+                self.held[..held_len]
+                    .copy_from_slice(&self.decoded_buffer[self.decoded_len..raw_decoded_len]);
+                self.held_len = held_len;
+            } else {
+                self.decoded_len = raw_decoded_len;
+            }
+
+            self.b64_consumed += quad_bytes;
+
+            // shift the leftover (< 4) bytes down to the front of the buffer
+            let leftover = self.b64_len - quad_bytes;
+            self.b64_buffer.copy_within(quad_bytes..quad_bytes + leftover, 0);
+            self.b64_len = leftover;
+        }
+
+        Ok(())
+    }
+
+    // Move any strict-mode held-back bytes into `released`, now that we know it's safe to hand
+    // them out.
+    fn release_held(&mut self) {
+        if self.held_len > 0 {
+            self.released[..self.held_len].copy_from_slice(&self.held[..self.held_len]);
+            self.released_len = self.held_len;
+            self.released_offset = 0;
+            self.held_len = 0;
+        }
+    }
+
+    // Shared by both the `std::io::Read` and `crate::io::Read` impls below.
+    fn read_decoded(&mut self, buf: &mut [u8]) -> Result<usize, ReadError<R::Error>> {
+        if self.released_remaining().is_empty() && self.decoded_remaining().is_empty() {
+            if self.at_eof && self.held_len == 0 {
+                return Ok(0);
+            }
+            self.fill_decoded_buffer()?;
+        }
+
+        let available = if !self.released_remaining().is_empty() {
+            self.released_remaining()
+        } else {
+            self.decoded_remaining()
+        };
+        let to_copy = core::cmp::min(buf.len(), available.len());
+        buf[..to_copy].copy_from_slice(&available[..to_copy]);
+        if self.released_offset < self.released_len {
+            self.released_offset += to_copy;
+        } else {
+            self.decoded_offset += to_copy;
+        }
+        self.decoded_pos += to_copy as u64;
+        Ok(to_copy)
+    }
+}
+
+// Re-base a `DecodeError`'s offset (which is relative to the chunk that was just decoded) onto
+// the overall base64 stream.
+fn offset_error(e: DecodeError, base: usize) -> DecodeError {
+    match e {
+        DecodeError::InvalidByte(offset, byte) => DecodeError::InvalidByte(base + offset, byte),
+        DecodeError::InvalidLastSymbol(offset, byte) => {
+            DecodeError::InvalidLastSymbol(base + offset, byte)
+        }
+        other => other,
+    }
+}
+
+// Without `std`, `DecoderReader` implements our own `Read` directly. With `std`, it instead
+// implements `std::io::Read` below, which (via the blanket impl in `crate::io`) gives it this
+// trait for free - implementing both directly would conflict.
+#[cfg(not(feature = "std"))]
+impl<'e, E: Engine, R: Read> Read for DecoderReader<'e, E, R> {
+    type Error = ReadError<R::Error>;
+
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        self.read_decoded(buf)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'e, E: Engine, R: Read<Error = std::io::Error>> std::io::Read for DecoderReader<'e, E, R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.read_decoded(buf).map_err(Into::into)
+    }
+}
+
+/// Seeking is supported whenever the delegate is itself seekable, since base64's fixed 4:3
+/// ratio makes the decoded↔encoded offset mapping exact. This assumes unwrapped base64 (no
+/// interspersed newlines): `DecoderReader` doesn't understand line wrapping at all, so seeking
+/// works the same way reading already does.
+#[cfg(feature = "std")]
+impl<'e, E: Engine, R: Read<Error = std::io::Error> + Seek> Seek for DecoderReader<'e, E, R> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let target = match pos {
+            SeekFrom::Start(n) => n,
+            SeekFrom::Current(delta) => add_signed(self.decoded_pos, delta)?,
+            SeekFrom::End(delta) => add_signed(self.decoded_stream_len()?, delta)?,
+        };
+
+        self.seek_to_decoded_offset(target)?;
+        Ok(target)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'e, E: Engine, R: Read<Error = std::io::Error> + Seek> DecoderReader<'e, E, R> {
+    // Seek the delegate to the encoded position for decoded offset `target`, reset our internal
+    // state, and decode-and-discard the leading `target % 3` bytes of the quad it lands in so
+    // that the next call to `read` yields decoded byte `target`.
+    fn seek_to_decoded_offset(&mut self, target: u64) -> std::io::Result<()> {
+        let quad = target / 3;
+        let rem = (target % 3) as usize;
+
+        self.delegate.seek(SeekFrom::Start(quad * 4))?;
+
+        self.b64_len = 0;
+        self.decoded_len = 0;
+        self.decoded_offset = 0;
+        self.released_len = 0;
+        self.released_offset = 0;
+        self.held_len = 0;
+        self.b64_consumed = (quad * 4) as usize;
+        self.at_eof = false;
+        self.decoded_pos = target - rem as u64;
+
+        if rem > 0 {
+            self.fill_decoded_buffer()?;
+            // The target quad's bytes are the first ones `read` would hand out. In strict mode
+            // `fill_decoded_buffer` may have moved some or all of them into `released` (served
+            // ahead of `decoded_buffer`), so skip `rem` bytes across both, in that order.
+            let from_released = core::cmp::min(rem, self.released_remaining().len());
+            let from_decoded = rem - from_released;
+            if from_decoded > self.decoded_remaining().len() {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::UnexpectedEof,
+                    "seek target is past the end of the decoded stream",
+                ));
+            }
+            self.released_offset += from_released;
+            self.decoded_offset += from_decoded;
+            self.decoded_pos += rem as u64;
+        }
+
+        Ok(())
+    }
+
+    // The total length of the decoded stream, derived from the delegate's encoded length and
+    // padding, without consuming any of our already-buffered state. Only available when the
+    // engine's config has encode padding enabled, since otherwise the encoded length alone doesn't determine how
+    // many trailing bytes the final (unpadded) quad decodes to.
+    fn decoded_stream_len(&mut self) -> std::io::Result<u64> {
+        if !self.engine.config().encode_padding() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "SeekFrom::End requires a padded engine to determine the decoded length",
+            ));
+        }
+
+        let saved_pos = self.delegate.stream_position()?;
+        let enc_len = self.delegate.seek(SeekFrom::End(0))?;
+        if !enc_len.is_multiple_of(4) {
+            self.delegate.seek(SeekFrom::Start(saved_pos))?;
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                DecodeError::InvalidLength,
+            ));
+        }
+
+        let pad_count = if enc_len == 0 {
+            0
+        } else {
+            let mut last_quad = [0u8; 4];
+            self.delegate.seek(SeekFrom::Start(enc_len - 4))?;
+            read_exact(&mut self.delegate, &mut last_quad)?;
+            last_quad.iter().rev().take_while(|&&b| b == PAD_BYTE).count() as u64
+        };
+
+        self.delegate.seek(SeekFrom::Start(saved_pos))?;
+
+        Ok(enc_len / 4 * 3 - pad_count)
+    }
+}
+
+// `crate::io::Read` has no `read_exact`, so fill `buf` by looping `read` ourselves.
+#[cfg(feature = "std")]
+fn read_exact<D: Read<Error = std::io::Error>>(
+    delegate: &mut D,
+    mut buf: &mut [u8],
+) -> std::io::Result<()> {
+    while !buf.is_empty() {
+        match delegate.read(buf)? {
+            0 => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::UnexpectedEof,
+                    "failed to fill whole buffer",
+                ))
+            }
+            n => buf = &mut buf[n..],
+        }
+    }
+    Ok(())
+}
+
+#[cfg(feature = "std")]
+fn add_signed(pos: u64, delta: i64) -> std::io::Result<u64> {
+    let result = if delta < 0 {
+        pos.checked_sub(delta.unsigned_abs())
+    } else {
+        pos.checked_add(delta as u64)
+    };
+
+    result.ok_or_else(|| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "invalid seek to a negative or overflowing position",
+        )
+    })
+}
@@ -0,0 +1,196 @@
+//! An async mirror of [`super::decoder::DecoderReader`], for callers who can't block on I/O.
+//!
+//! Requires the `tokio-io` and/or `futures-io` features, which respectively implement
+//! `tokio::io::AsyncRead` and `futures::io::AsyncRead` for [`AsyncDecoderReader`].
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use crate::engine::Engine;
+use crate::read::decoder::BUF_SIZE;
+use crate::DecodeError;
+
+const DECODED_BUF_SIZE: usize = BUF_SIZE / 4 * 3;
+
+/// An async `Read` implementation that decodes base64 data read from a wrapped async delegate.
+///
+/// Unlike the blocking [`DecoderReader`](super::decoder::DecoderReader), a `poll_read` on the
+/// delegate can return `Pending` partway through a quad, so all of the partial-quad and
+/// not-yet-flushed-decoded-bytes state has to survive across poll calls instead of living on the
+/// stack of a single blocking loop, exactly like the fields below.
+pub struct AsyncDecoderReader<'e, E: Engine, R> {
+    engine: &'e E,
+    delegate: R,
+
+    // Undecoded bytes read from the delegate. Holds a partial quad (0-3 bytes) across poll
+    // calls, exactly like the blocking reader, plus whatever an in-progress `Pending` delegate
+    // read hasn't finished filling yet.
+    b64_buffer: [u8; BUF_SIZE],
+    b64_len: usize,
+
+    // Decoded bytes waiting to be handed out via `poll_read`.
+    decoded_buffer: [u8; DECODED_BUF_SIZE],
+    decoded_len: usize,
+    decoded_offset: usize,
+
+    // How many encoded bytes have been consumed so far, for accurate decode error offsets.
+    b64_consumed: usize,
+
+    at_eof: bool,
+}
+
+impl<'e, E: Engine, R> AsyncDecoderReader<'e, E, R> {
+    /// Create a new async decoding reader that reads base64 data from `delegate` and decodes it
+    /// with `engine`.
+    pub fn from(delegate: R, engine: &'e E) -> Self {
+        AsyncDecoderReader {
+            engine,
+            delegate,
+            b64_buffer: [0; BUF_SIZE],
+            b64_len: 0,
+            decoded_buffer: [0; DECODED_BUF_SIZE],
+            decoded_len: 0,
+            decoded_offset: 0,
+            b64_consumed: 0,
+            at_eof: false,
+        }
+    }
+
+    fn decoded_remaining(&self) -> &[u8] {
+        &self.decoded_buffer[self.decoded_offset..self.decoded_len]
+    }
+
+    #[cfg(feature = "futures-io")]
+    fn take_decoded(&mut self, buf: &mut [u8]) -> usize {
+        let available = self.decoded_remaining();
+        let to_copy = std::cmp::min(buf.len(), available.len());
+        buf[..to_copy].copy_from_slice(&available[..to_copy]);
+        self.decoded_offset += to_copy;
+        to_copy
+    }
+
+    // Called once a delegate read reports `read` new bytes appended to `b64_buffer` (or 0 for
+    // EOF). Decodes as many complete quads as are now available, leaving 0-3 leftover encoded
+    // bytes for the next delegate read to complete.
+    fn on_delegate_read(&mut self, read: usize) -> std::io::Result<()> {
+        if read == 0 {
+            self.at_eof = true;
+            if !self.b64_len.is_multiple_of(4) {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    DecodeError::InvalidLength,
+                ));
+            }
+        }
+        self.b64_len += read;
+
+        let quad_bytes = self.b64_len / 4 * 4;
+        self.decoded_offset = 0;
+        if quad_bytes == 0 {
+            self.decoded_len = 0;
+            return Ok(());
+        }
+
+        self.decoded_len = self
+            .engine
+            .internal_decode(&self.b64_buffer[..quad_bytes], &mut self.decoded_buffer)
+            .map_err(|e| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    offset_error(e, self.b64_consumed),
+                )
+            })?;
+        self.b64_consumed += quad_bytes;
+
+        let leftover = self.b64_len - quad_bytes;
+        self.b64_buffer.copy_within(quad_bytes..quad_bytes + leftover, 0);
+        self.b64_len = leftover;
+
+        Ok(())
+    }
+}
+
+fn offset_error(e: DecodeError, base: usize) -> DecodeError {
+    match e {
+        DecodeError::InvalidByte(offset, byte) => DecodeError::InvalidByte(base + offset, byte),
+        DecodeError::InvalidLastSymbol(offset, byte) => {
+            DecodeError::InvalidLastSymbol(base + offset, byte)
+        }
+        other => other,
+    }
+}
+
+#[cfg(feature = "tokio-io")]
+mod tokio_impl {
+    use super::*;
+    use tokio::io::{AsyncRead, ReadBuf};
+
+    impl<'e, E: Engine, R: AsyncRead + Unpin> AsyncRead for AsyncDecoderReader<'e, E, R> {
+        fn poll_read(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut ReadBuf<'_>,
+        ) -> Poll<std::io::Result<()>> {
+            let this = self.get_mut();
+            loop {
+                if !this.decoded_remaining().is_empty() {
+                    let avail = this.decoded_remaining();
+                    let to_copy = std::cmp::min(buf.remaining(), avail.len());
+                    buf.put_slice(&avail[..to_copy]);
+                    this.decoded_offset += to_copy;
+                    return Poll::Ready(Ok(()));
+                }
+                if this.at_eof {
+                    return Poll::Ready(Ok(()));
+                }
+
+                let dst = &mut this.b64_buffer[this.b64_len..];
+                let mut read_buf = ReadBuf::new(dst);
+                match Pin::new(&mut this.delegate).poll_read(cx, &mut read_buf) {
+                    Poll::Ready(Ok(())) => {
+                        let read = read_buf.filled().len();
+                        if let Err(e) = this.on_delegate_read(read) {
+                            return Poll::Ready(Err(e));
+                        }
+                    }
+                    Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                    Poll::Pending => return Poll::Pending,
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "futures-io")]
+mod futures_impl {
+    use super::*;
+    use futures::io::AsyncRead;
+
+    impl<'e, E: Engine, R: AsyncRead + Unpin> AsyncRead for AsyncDecoderReader<'e, E, R> {
+        fn poll_read(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut [u8],
+        ) -> Poll<std::io::Result<usize>> {
+            let this = self.get_mut();
+            loop {
+                if !this.decoded_remaining().is_empty() {
+                    return Poll::Ready(Ok(this.take_decoded(buf)));
+                }
+                if this.at_eof {
+                    return Poll::Ready(Ok(0));
+                }
+
+                let dst = &mut this.b64_buffer[this.b64_len..];
+                match Pin::new(&mut this.delegate).poll_read(cx, dst) {
+                    Poll::Ready(Ok(read)) => {
+                        if let Err(e) = this.on_delegate_read(read) {
+                            return Poll::Ready(Err(e));
+                        }
+                    }
+                    Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                    Poll::Pending => return Poll::Pending,
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,106 @@
+// Covers both async backends `AsyncDecoderReader` can run on; each gets its own inner module
+// since they pull in different executors and `AsyncRead` traits.
+
+#[cfg(feature = "futures-io")]
+mod futures_tests {
+    use futures::io::{AsyncReadExt, Cursor};
+
+    use crate::engine::DEFAULT_ENGINE;
+    use crate::read::AsyncDecoderReader;
+    use crate::DecodeError;
+
+    #[test]
+    fn decodes_same_as_blocking_reader() {
+        let mut decoder =
+            AsyncDecoderReader::from(Cursor::new(&b"aGVsbG8gd29ybGQ="[..]), &DEFAULT_ENGINE);
+        let mut got = Vec::new();
+        futures::executor::block_on(decoder.read_to_end(&mut got)).unwrap();
+        assert_eq!(b"hello world", &got[..]);
+    }
+
+    // Same invalid-byte input as `decoder_tests::reports_invalid_byte_correctly`; the async
+    // reader should map `DecodeError` the same way the blocking one does.
+    #[test]
+    fn reports_invalid_byte_correctly() {
+        let mut decoder =
+            AsyncDecoderReader::from(Cursor::new(&b"aGVsbG8*d29ybGQ="[..]), &DEFAULT_ENGINE);
+        let mut got = Vec::new();
+        let err = futures::executor::block_on(decoder.read_to_end(&mut got)).unwrap_err();
+        assert_eq!(
+            &DecodeError::InvalidByte(7, b'*'),
+            err.into_inner()
+                .unwrap()
+                .downcast::<DecodeError>()
+                .unwrap()
+                .as_ref()
+        );
+    }
+}
+
+#[cfg(feature = "tokio-io")]
+mod tokio_tests {
+    use tokio::io::AsyncReadExt;
+
+    use crate::engine::DEFAULT_ENGINE;
+    use crate::read::AsyncDecoderReader;
+    use crate::DecodeError;
+
+    fn block_on<F: std::future::Future>(f: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(f)
+    }
+
+    // Same input as the futures-io backend's test above, exercised through `tokio::io::AsyncRead`
+    // (`poll_read`'s `ReadBuf`-based path) instead.
+    #[test]
+    fn decodes_same_as_blocking_reader() {
+        let mut decoder =
+            AsyncDecoderReader::from(std::io::Cursor::new(&b"aGVsbG8gd29ybGQ="[..]), &DEFAULT_ENGINE);
+        let mut got = Vec::new();
+        block_on(decoder.read_to_end(&mut got)).unwrap();
+        assert_eq!(b"hello world", &got[..]);
+    }
+
+    #[test]
+    fn reports_invalid_byte_correctly() {
+        let mut decoder =
+            AsyncDecoderReader::from(std::io::Cursor::new(&b"aGVsbG8*d29ybGQ="[..]), &DEFAULT_ENGINE);
+        let mut got = Vec::new();
+        let err = block_on(decoder.read_to_end(&mut got)).unwrap_err();
+        assert_eq!(
+            &DecodeError::InvalidByte(7, b'*'),
+            err.into_inner()
+                .unwrap()
+                .downcast::<DecodeError>()
+                .unwrap()
+                .as_ref()
+        );
+    }
+
+    // `ReadBuf`'s filled region is only ever as large as the destination buffer passed to
+    // `poll_read`, so reading through a small buffer must still exercise multiple internal
+    // `b64_buffer` fills and produce the same bytes as reading it all at once.
+    #[test]
+    fn decodes_correctly_through_small_read_buffers() {
+        let expected = "012345678".repeat(100);
+        let mut b64 = String::new();
+        crate::encode::encode_engine_string(expected.as_bytes(), &mut b64, &DEFAULT_ENGINE);
+
+        let mut decoder =
+            AsyncDecoderReader::from(std::io::Cursor::new(b64.as_bytes()), &DEFAULT_ENGINE);
+        let mut got = Vec::new();
+        let mut buf = [0u8; 7];
+        block_on(async {
+            loop {
+                let n = decoder.read(&mut buf).await.unwrap();
+                if n == 0 {
+                    break;
+                }
+                got.extend_from_slice(&buf[..n]);
+            }
+        });
+        assert_eq!(expected.into_bytes(), got);
+    }
+}
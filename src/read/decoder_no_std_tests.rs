@@ -0,0 +1,62 @@
+// These only run under `cargo test --no-default-features`: with the `std` feature (the default),
+// `DecoderReader` implements `std::io::Read` instead (see `decoder_tests.rs`), and the two impls
+// can't coexist.
+extern crate std;
+use std::vec::Vec;
+
+use crate::engine::fast_portable::FastPortable;
+use crate::engine::{Config, DEFAULT_ENGINE};
+use crate::io::{Read, ReadError};
+use crate::read::DecoderReader;
+use crate::DecodeError;
+
+// A minimal `crate::io::Read` byte source, standing in for whatever no-`std` embedders actually
+// read from (a ring buffer, a peripheral, etc).
+struct SliceReader<'a> {
+    remaining: &'a [u8],
+}
+
+impl<'a> Read for SliceReader<'a> {
+    type Error = ();
+
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        let n = core::cmp::min(buf.len(), self.remaining.len());
+        buf[..n].copy_from_slice(&self.remaining[..n]);
+        self.remaining = &self.remaining[n..];
+        Ok(n)
+    }
+}
+
+fn read_all(decoder: &mut DecoderReader<FastPortable, SliceReader>) -> Result<Vec<u8>, ReadError<()>> {
+    let mut out = Vec::new();
+    let mut buf = [0u8; 4];
+    loop {
+        let n = decoder.read(&mut buf)?;
+        if n == 0 {
+            return Ok(out);
+        }
+        out.extend_from_slice(&buf[..n]);
+    }
+}
+
+#[test]
+fn decodes_over_crate_io_read() {
+    let delegate = SliceReader {
+        remaining: b"aGVsbG8gd29ybGQ=",
+    };
+    let mut decoder = DecoderReader::from(delegate, &DEFAULT_ENGINE);
+    assert_eq!(b"hello world".to_vec(), read_all(&mut decoder).unwrap());
+}
+
+#[test]
+fn reports_invalid_byte_over_crate_io_read() {
+    let delegate = SliceReader {
+        remaining: b"aGVsbG8*d29ybGQ=",
+    };
+    let engine = FastPortable::from(&crate::engine::fast_portable::Alphabet::STANDARD, Config::new());
+    let mut decoder = DecoderReader::from(delegate, &engine);
+    assert_eq!(
+        Err(ReadError::Decode(DecodeError::InvalidByte(7, b'*'))),
+        read_all(&mut decoder)
+    );
+}
@@ -52,6 +52,70 @@ fn simple() {
     }
 }
 
+// Seeking should land on the same bytes that reading from scratch would yield, for every
+// decoded offset into the stream, including ones that fall mid-quad.
+#[test]
+fn seek_to_arbitrary_decoded_offsets() {
+    use std::io::{Seek, SeekFrom};
+
+    let text = b"the quick brown fox jumps over the lazy dog";
+    let mut b64 = String::new();
+    encode_engine_string(&text[..], &mut b64, &DEFAULT_ENGINE);
+
+    for target in 0..=text.len() {
+        let mut wrapped_reader = io::Cursor::new(b64.as_bytes());
+        let mut decoder = DecoderReader::from(&mut wrapped_reader, &DEFAULT_ENGINE);
+
+        let pos = decoder.seek(SeekFrom::Start(target as u64)).unwrap();
+        assert_eq!(target as u64, pos);
+
+        let mut got = Vec::new();
+        decoder.read_to_end(&mut got).unwrap();
+        assert_eq!(&text[target..], &got[..], "seeking to decoded offset {target}");
+    }
+
+    // `SeekFrom::End` is only supported for a padded engine with a known encoded length.
+    let mut wrapped_reader = io::Cursor::new(b64.as_bytes());
+    let mut decoder = DecoderReader::from(&mut wrapped_reader, &DEFAULT_ENGINE);
+    let pos = decoder.seek(SeekFrom::End(-3)).unwrap();
+    assert_eq!((text.len() - 3) as u64, pos);
+    let mut got = Vec::new();
+    decoder.read_to_end(&mut got).unwrap();
+    assert_eq!(&text[text.len() - 3..], &got[..]);
+}
+
+// Seeking in strict mode must work whether `fill_decoded_buffer` leaves the seek target's bytes
+// held (more data may still arrive) or, for an in-memory delegate that resolves EOF within the
+// same call, already released (EOF confirmed it's the stream's true final group) - and a seek
+// exactly to EOF is legal, while one byte further is not.
+#[test]
+fn seek_to_arbitrary_decoded_offsets_in_strict_mode() {
+    use std::io::{Seek, SeekFrom};
+
+    let text = b"the quick brown fox jumps over the lazy dog";
+    let mut b64 = String::new();
+    encode_engine_string(&text[..], &mut b64, &DEFAULT_ENGINE);
+
+    for target in 0..=text.len() {
+        let mut wrapped_reader = io::Cursor::new(b64.as_bytes());
+        let mut decoder =
+            DecoderReader::from(&mut wrapped_reader, &DEFAULT_ENGINE).with_strict_mode(true);
+
+        let pos = decoder.seek(SeekFrom::Start(target as u64)).unwrap();
+        assert_eq!(target as u64, pos);
+
+        let mut got = Vec::new();
+        decoder.read_to_end(&mut got).unwrap();
+        assert_eq!(&text[target..], &got[..], "seeking to decoded offset {target}");
+    }
+
+    // One byte past the end is a genuinely invalid seek target.
+    let mut wrapped_reader = io::Cursor::new(b64.as_bytes());
+    let mut decoder =
+        DecoderReader::from(&mut wrapped_reader, &DEFAULT_ENGINE).with_strict_mode(true);
+    assert!(decoder.seek(SeekFrom::Start(text.len() as u64 + 1)).is_err());
+}
+
 // Make sure we error out on trailing junk.
 #[test]
 fn trailing_junk() {
@@ -72,7 +136,7 @@ fn trailing_junk() {
                         saw_error = true;
                         break;
                     }
-                    Ok(read) if read == 0 => break,
+                    Ok(0) => break,
                     Ok(_) => (),
                 }
             }
@@ -82,6 +146,86 @@ fn trailing_junk() {
     }
 }
 
+// A delegate that yields `first`, then (once that's exhausted) `second`, then EOF - used to put
+// a valid prefix and a malformed tail in separate `fill_decoded_buffer` batches, the way they'd
+// naturally arrive from a real streaming source.
+struct TwoPartReader<'a> {
+    parts: [&'a [u8]; 2],
+}
+
+impl<'a> io::Read for TwoPartReader<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        for part in &mut self.parts {
+            if !part.is_empty() {
+                let n = cmp::min(buf.len(), part.len());
+                buf[..n].copy_from_slice(&part[..n]);
+                *part = &part[n..];
+                return Ok(n);
+            }
+        }
+        Ok(0)
+    }
+}
+
+// In strict mode, a stream whose final group turns out to be malformed must never hand out any
+// bytes from that group, even though a non-strict reader would already have released them as
+// decoded - as long as the problem only shows up in a *later* `fill_decoded_buffer` batch, the
+// same way `trailing_junk` and `reports_invalid_last_symbol_correctly` provoke it for the
+// non-strict reader. `io::Cursor` hands over an entire small input in one `read`, which would
+// decode (and fail) the whole thing in a single batch and prove nothing about holding bytes back
+// across batches, so each case below is split across `TwoPartReader`'s two reads at the point
+// where a real streaming source would plausibly have paused.
+#[test]
+fn strict_mode_never_leaks_bytes_from_an_invalid_final_group() {
+    // Trailing junk after a complete, unpadded stream: "MDEyMzQ1Njc4" (3 full groups) decodes to
+    // "012345678" and arrives whole in the first read, so strict mode holds back its last group
+    // ("678") and releases only "012345"; the junk then fails to decode in the second read,
+    // and "678" must never be released.
+    check_final_group_never_leaks(b"MDEyMzQ1Njc4", b"*!@#$%^&", b"012345");
+
+    // Trailing junk after padding: "MDEyMzQ1Njc4OQ==" (4 groups, the last padded) decodes to
+    // "0123456789"; strict mode holds back only the padded last group ("9"), releasing the other
+    // three groups ("012345678") before the junk arrives and fails.
+    check_final_group_never_leaks(b"MDEyMzQ1Njc4OQ==", b" ", b"012345678");
+
+    // Bad last symbol: the first read completes only 2 groups ("MDEyMzQ1" -> "012345"), so
+    // strict mode holds back the second group ("345") pending confirmation; the second read
+    // delivers "NjB=" - "Njc=" ("67", padded) with its last symbol swapped for one whose low
+    // discarded bits aren't zero - which fails to decode, so "345" must never be released.
+    check_final_group_never_leaks(b"MDEyMzQ1", b"NjB=", b"012");
+
+    // A genuinely valid stream should still release all of its bytes in strict mode.
+    let mut wrapped_reader = io::Cursor::new(&b"MDEyMzQ1Njc4OQ=="[..]);
+    let mut decoder =
+        DecoderReader::from(&mut wrapped_reader, &DEFAULT_ENGINE).with_strict_mode(true);
+    let mut got = Vec::new();
+    decoder.read_to_end(&mut got).unwrap();
+    assert_eq!(b"0123456789", &got[..]);
+}
+
+// Feeds `first` then `second` (in separate reads) to a strict-mode `DecoderReader`, asserting
+// that exactly `safe_prefix` is released before `second` fails to decode, and that nothing more
+// ever comes out.
+fn check_final_group_never_leaks(first: &[u8], second: &[u8], safe_prefix: &[u8]) {
+    let mut wrapped_reader = TwoPartReader {
+        parts: [first, second],
+    };
+    let mut decoder =
+        DecoderReader::from(&mut wrapped_reader, &DEFAULT_ENGINE).with_strict_mode(true);
+
+    let mut buffer = vec![0u8; safe_prefix.len()];
+    decoder.read_exact(&mut buffer).unwrap();
+    assert_eq!(safe_prefix, &buffer[..]);
+
+    let mut rest = Vec::new();
+    let err = decoder.read_to_end(&mut rest).unwrap_err();
+    assert!(rest.is_empty(), "leaked bytes from an unconfirmed final group: {rest:?}");
+    assert!(err
+        .into_inner()
+        .and_then(|e| e.downcast::<DecodeError>().ok())
+        .is_some());
+}
+
 #[test]
 fn handles_short_read_from_delegate() {
     let mut rng = rand::thread_rng();
@@ -95,7 +239,7 @@ fn handles_short_read_from_delegate() {
         decoded.clear();
 
         let size = rng.gen_range(0, 10 * BUF_SIZE);
-        bytes.extend(iter::repeat(0).take(size));
+        bytes.extend(iter::repeat_n(0, size));
         bytes.truncate(size);
         rng.fill_bytes(&mut bytes[..size]);
         assert_eq!(size, bytes.len());
@@ -130,9 +274,9 @@ fn read_in_short_increments() {
         decoded.clear();
 
         let size = rng.gen_range(0, 10 * BUF_SIZE);
-        bytes.extend(iter::repeat(0).take(size));
+        bytes.extend(iter::repeat_n(0, size));
         // leave room to play around with larger buffers
-        decoded.extend(iter::repeat(0).take(size * 3));
+        decoded.extend(iter::repeat_n(0, size * 3));
 
         rng.fill_bytes(&mut bytes[..]);
         assert_eq!(size, bytes.len());
@@ -161,9 +305,9 @@ fn read_in_short_increments_with_short_delegate_reads() {
         decoded.clear();
 
         let size = rng.gen_range(0, 10 * BUF_SIZE);
-        bytes.extend(iter::repeat(0).take(size));
+        bytes.extend(iter::repeat_n(0, size));
         // leave room to play around with larger buffers
-        decoded.extend(iter::repeat(0).take(size * 3));
+        decoded.extend(iter::repeat_n(0, size * 3));
 
         rng.fill_bytes(&mut bytes[..]);
         assert_eq!(size, bytes.len());
@@ -198,8 +342,8 @@ fn reports_invalid_last_symbol_correctly() {
         b64_bytes.clear();
 
         let size = rng.gen_range(1, 10 * BUF_SIZE);
-        bytes.extend(iter::repeat(0).take(size));
-        decoded.extend(iter::repeat(0).take(size));
+        bytes.extend(iter::repeat_n(0, size));
+        decoded.extend(iter::repeat_n(0, size));
         rng.fill_bytes(&mut bytes[..]);
         assert_eq!(size, bytes.len());
 
@@ -247,7 +391,7 @@ fn reports_invalid_byte_correctly() {
         decoded.clear();
 
         let size = rng.gen_range(1, 10 * BUF_SIZE);
-        bytes.extend(iter::repeat(0).take(size));
+        bytes.extend(iter::repeat_n(0, size));
         rng.fill_bytes(&mut bytes[..size]);
         assert_eq!(size, bytes.len());
 
@@ -255,7 +399,7 @@ fn reports_invalid_byte_correctly() {
 
         encode_engine_string(&bytes[..], &mut b64, &engine);
         // replace one byte, somewhere, with '*', which is invalid
-        let bad_byte_pos = rng.gen_range(0, &b64.len());
+        let bad_byte_pos = rng.gen_range(0, b64.len());
         let mut b64_bytes = b64.bytes().collect::<Vec<u8>>();
         b64_bytes[bad_byte_pos] = b'*';
 
@@ -291,9 +435,9 @@ fn reports_invalid_byte_correctly() {
 fn consume_with_short_reads_and_validate<R: Read>(
     rng: &mut rand::rngs::ThreadRng,
     expected_bytes: &[u8],
-    decoded: &mut Vec<u8>,
+    decoded: &mut [u8],
     short_reader: &mut R,
-) -> () {
+) {
     let mut total_read = 0_usize;
     loop {
         assert!(
@@ -0,0 +1,15 @@
+//! Implementations of `std::io::Read` (or, without the `std` feature, [`crate::io::Read`]) to
+//! transparently decode base64.
+mod decoder;
+#[cfg(all(test, feature = "std"))]
+mod decoder_tests;
+#[cfg(all(test, not(feature = "std")))]
+mod decoder_no_std_tests;
+#[cfg(any(feature = "tokio-io", feature = "futures-io"))]
+mod decoder_async;
+#[cfg(all(test, any(feature = "tokio-io", feature = "futures-io")))]
+mod decoder_async_tests;
+
+pub use self::decoder::DecoderReader;
+#[cfg(any(feature = "tokio-io", feature = "futures-io"))]
+pub use self::decoder_async::AsyncDecoderReader;
@@ -0,0 +1,27 @@
+//! Shared helpers for generating random test inputs, used by the unit tests scattered across
+//! this crate.
+
+use rand::Rng;
+
+use crate::engine::fast_portable::{Alphabet, FastPortable};
+use crate::engine::Config;
+
+pub fn random_alphabet<R: Rng>(rng: &mut R) -> &'static Alphabet {
+    if rng.gen() {
+        &Alphabet::STANDARD
+    } else {
+        &Alphabet::URL_SAFE
+    }
+}
+
+pub fn random_config<R: Rng>(rng: &mut R) -> Config {
+    Config::new()
+        .with_encode_padding(rng.gen())
+        .with_decode_allow_trailing_bits(rng.gen())
+}
+
+pub fn random_engine<R: Rng>(rng: &mut R) -> FastPortable {
+    let alphabet = random_alphabet(rng);
+    let config = random_config(rng);
+    FastPortable::from(alphabet, config)
+}
@@ -0,0 +1,137 @@
+use std::io;
+
+use crate::engine::Engine;
+use crate::DecodeError;
+
+const PAD_BYTE: u8 = b'=';
+
+/// A `Write` implementation that base64-decodes data written to it before forwarding the
+/// decoded bytes to a wrapped delegate writer.
+///
+/// Mirrors [`EncoderWriter`](super::EncoderWriter), but for decoding: useful when base64 text
+/// arrives in arbitrary chunks (e.g. off a socket) and the consumer is itself sink-shaped.
+///
+/// The last 4 to 7 bytes written (all of them, while fewer than 4 have arrived) are always held
+/// back internally: the last complete quad, plus any incomplete one after it, since whether that
+/// quad is the possibly-padded final one can't be known until either more data arrives or the
+/// caller calls [`finish`](DecoderWriter::finish). Callers must call `finish` once all data has
+/// been written, or the final quad will never be decoded and flushed.
+pub struct DecoderWriter<'e, E: Engine, W: io::Write> {
+    engine: &'e E,
+    delegate: Option<W>,
+
+    // base64 bytes not yet decoded: everything but the final quad is decoded and forwarded as
+    // soon as a `write` call completes it, so this holds at most one held-back quad (0-4 bytes)
+    // plus whatever incomplete quad is still arriving.
+    pending: Vec<u8>,
+
+    // How many encoded bytes have already been decoded and forwarded, so that decode errors can
+    // be reported at their true offset into the overall stream rather than just the current
+    // `write` call's chunk.
+    b64_consumed: usize,
+}
+
+impl<'e, E: Engine, W: io::Write> DecoderWriter<'e, E, W> {
+    /// Create a new decoding writer that decodes base64 text written to it with `engine` and
+    /// writes the decoded bytes to `delegate`.
+    pub fn from(delegate: W, engine: &'e E) -> Self {
+        DecoderWriter {
+            engine,
+            delegate: Some(delegate),
+            pending: Vec::new(),
+            b64_consumed: 0,
+        }
+    }
+
+    /// Decode and write out the final held-back quad (validating its padding), then return the
+    /// wrapped writer.
+    ///
+    /// This must be called after the last `write`, or the final quad will never be flushed.
+    pub fn finish(&mut self) -> io::Result<W> {
+        if !self.pending.is_empty() {
+            if self.pending.len() != 4 {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    DecodeError::InvalidLength,
+                ));
+            }
+
+            let mut out = [0u8; 3];
+            let decoded_len = self
+                .engine
+                .internal_decode(&self.pending, &mut out)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, offset_error(e, self.b64_consumed)))?;
+            self.delegate
+                .as_mut()
+                .expect("finish called more than once")
+                .write_all(&out[..decoded_len])?;
+            self.pending.clear();
+        }
+
+        Ok(self.delegate.take().expect("finish called more than once"))
+    }
+
+    // Decode `input`, which must hold zero or more complete quads and *no* padding (since
+    // padding is only ever legal in the final quad, which callers always hold back separately),
+    // and forward the decoded bytes to the delegate.
+    fn decode_and_forward(&mut self, input: &[u8]) -> io::Result<()> {
+        if input.is_empty() {
+            return Ok(());
+        }
+
+        if let Some(pos) = input.iter().position(|&b| b == PAD_BYTE) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                offset_error(DecodeError::InvalidByte(pos, PAD_BYTE), self.b64_consumed),
+            ));
+        }
+
+        let mut out = vec![0u8; input.len() / 4 * 3];
+        let decoded_len = self
+            .engine
+            .internal_decode(input, &mut out)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, offset_error(e, self.b64_consumed)))?;
+        self.delegate
+            .as_mut()
+            .expect("write called after finish")
+            .write_all(&out[..decoded_len])?;
+        self.b64_consumed += input.len();
+        Ok(())
+    }
+}
+
+// Re-base a `DecodeError`'s offset (which is relative to the chunk that was just decoded) onto
+// the overall base64 stream, mirroring `read::decoder::offset_error`.
+fn offset_error(e: DecodeError, base: usize) -> DecodeError {
+    match e {
+        DecodeError::InvalidByte(offset, byte) => DecodeError::InvalidByte(base + offset, byte),
+        DecodeError::InvalidLastSymbol(offset, byte) => {
+            DecodeError::InvalidLastSymbol(base + offset, byte)
+        }
+        other => other,
+    }
+}
+
+impl<'e, E: Engine, W: io::Write> io::Write for DecoderWriter<'e, E, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let original_len = buf.len();
+        self.pending.extend_from_slice(buf);
+
+        // Always keep at least the last 4 bytes un-decoded: they might be the final, padded
+        // quad, and we can't tell until `finish` or more data arrives.
+        let decode_len = self.pending.len().saturating_sub(4) / 4 * 4;
+        if decode_len > 0 {
+            let to_decode = self.pending.drain(..decode_len).collect::<Vec<u8>>();
+            self.decode_and_forward(&to_decode)?;
+        }
+
+        Ok(original_len)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.delegate
+            .as_mut()
+            .expect("flush called after finish")
+            .flush()
+    }
+}
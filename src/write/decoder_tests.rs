@@ -0,0 +1,82 @@
+use std::io::Write;
+
+use rand::{Rng, RngCore};
+
+use super::decoder::DecoderWriter;
+use crate::encode::encode_engine_string;
+use crate::engine::DEFAULT_ENGINE;
+use crate::tests::random_engine;
+use crate::{decode_engine_vec, DecodeError};
+
+// Writing base64 text in arbitrary-sized chunks should decode to the same bytes as writing it
+// all at once, or decoding it in bulk via `decode_engine_vec`.
+#[test]
+fn streaming_write_matches_bulk_decode() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..1_000 {
+        let size = rng.gen_range(0, 1000);
+        let mut bytes = vec![0u8; size];
+        rng.fill_bytes(&mut bytes);
+
+        let mut b64 = String::new();
+        encode_engine_string(&bytes[..], &mut b64, &DEFAULT_ENGINE);
+        let b64_bytes = b64.into_bytes();
+
+        let mut bulk_decoded = Vec::new();
+        decode_engine_vec(&b64_bytes[..], &mut bulk_decoded, &DEFAULT_ENGINE).unwrap();
+        assert_eq!(bytes, bulk_decoded);
+
+        let mut decoded = Vec::new();
+        {
+            let mut writer = DecoderWriter::from(&mut decoded, &DEFAULT_ENGINE);
+            // write in small, uneven chunks to exercise buffering across `write` calls
+            for chunk in b64_bytes.chunks(3) {
+                writer.write_all(chunk).unwrap();
+            }
+            writer.finish().unwrap();
+        }
+
+        assert_eq!(bytes, decoded);
+    }
+}
+
+// A `DecodeError` from malformed base64 should surface the same way whether it's written all at
+// once, in small chunks, or decoded in bulk.
+#[test]
+fn reports_same_decode_error_as_bulk() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..1_000 {
+        let engine = random_engine(&mut rng);
+        let size = rng.gen_range(1, 1000);
+        let mut bytes = vec![0u8; size];
+        rng.fill_bytes(&mut bytes);
+
+        let mut b64 = String::new();
+        encode_engine_string(&bytes[..], &mut b64, &engine);
+        let mut b64_bytes = b64.into_bytes();
+        let bad_byte_pos = rng.gen_range(0, b64_bytes.len());
+        b64_bytes[bad_byte_pos] = b'*';
+
+        let mut bulk_decoded = Vec::new();
+        let bulk_err = decode_engine_vec(&b64_bytes[..], &mut bulk_decoded, &engine).unwrap_err();
+
+        let mut decoded = Vec::new();
+        let stream_err = {
+            let mut writer = DecoderWriter::from(&mut decoded, &engine);
+            writer
+                .write_all(&b64_bytes)
+                .map_err(|e| *e.into_inner().unwrap().downcast::<DecodeError>().unwrap())
+                .and_then(|()| {
+                    writer
+                        .finish()
+                        .map(|_| ())
+                        .map_err(|e| *e.into_inner().unwrap().downcast::<DecodeError>().unwrap())
+                })
+                .unwrap_err()
+        };
+
+        assert_eq!(bulk_err, stream_err);
+    }
+}
@@ -0,0 +1,99 @@
+use std::io;
+
+use crate::engine::Engine;
+
+const CHUNK_SIZE: usize = 3;
+
+/// A `Write` implementation that base64-encodes data before writing it to a wrapped delegate
+/// writer.
+///
+/// Bytes are buffered until a full 3-byte chunk is available so that encoding can be done in
+/// whole 4-symbol quads; call [`finish`](EncoderWriter::finish) to flush any final partial
+/// chunk (with padding) once all data has been written.
+pub struct EncoderWriter<'e, E: Engine, W: io::Write> {
+    engine: &'e E,
+    delegate: Option<W>,
+    // 0-2 bytes not yet encoded because they don't form a full chunk
+    extra: [u8; CHUNK_SIZE],
+    extra_len: usize,
+}
+
+impl<'e, E: Engine, W: io::Write> EncoderWriter<'e, E, W> {
+    /// Create a new encoding writer that writes base64 text to `delegate`, encoding with
+    /// `engine`.
+    pub fn from(delegate: W, engine: &'e E) -> Self {
+        EncoderWriter {
+            engine,
+            delegate: Some(delegate),
+            extra: [0; CHUNK_SIZE],
+            extra_len: 0,
+        }
+    }
+
+    /// Encode and write out any buffered data, then return the wrapped writer.
+    ///
+    /// This must be called after the last `write` or the final partial chunk will never be
+    /// flushed.
+    pub fn finish(&mut self) -> io::Result<W> {
+        if self.extra_len > 0 {
+            let mut buf = [0u8; 4];
+            self.engine
+                .internal_encode(&self.extra[..self.extra_len], &mut buf);
+            self.delegate.as_mut().unwrap().write_all(&buf)?;
+            self.extra_len = 0;
+        }
+
+        Ok(self.delegate.take().expect("finish called more than once"))
+    }
+}
+
+impl<'e, E: Engine, W: io::Write> io::Write for EncoderWriter<'e, E, W> {
+    fn write(&mut self, mut input: &[u8]) -> io::Result<usize> {
+        let original_len = input.len();
+        let delegate = self.delegate.as_mut().expect("write called after finish");
+
+        if self.extra_len > 0 {
+            let needed = CHUNK_SIZE - self.extra_len;
+            let take = std::cmp::min(needed, input.len());
+            self.extra[self.extra_len..self.extra_len + take].copy_from_slice(&input[..take]);
+            self.extra_len += take;
+            input = &input[take..];
+
+            if self.extra_len < CHUNK_SIZE {
+                return Ok(original_len);
+            }
+
+            let mut buf = [0u8; 4];
+            self.engine.internal_encode(&self.extra[..], &mut buf);
+            delegate.write_all(&buf)?;
+            self.extra_len = 0;
+        }
+
+        let mut encode_buf = [0u8; 4];
+        let mut chunks = input.chunks(CHUNK_SIZE);
+        let remainder = if input.len().is_multiple_of(CHUNK_SIZE) {
+            None
+        } else {
+            chunks.next_back()
+        };
+
+        for chunk in chunks {
+            self.engine.internal_encode(chunk, &mut encode_buf);
+            delegate.write_all(&encode_buf)?;
+        }
+
+        if let Some(remainder) = remainder {
+            self.extra[..remainder.len()].copy_from_slice(remainder);
+            self.extra_len = remainder.len();
+        }
+
+        Ok(original_len)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.delegate
+            .as_mut()
+            .expect("flush called after finish")
+            .flush()
+    }
+}
@@ -0,0 +1,56 @@
+use std::io::Write;
+
+use rand::{Rng, RngCore};
+
+use super::encoder::EncoderWriter;
+use crate::encode::encode_engine_string;
+use crate::engine::DEFAULT_ENGINE;
+
+// Writing bytes in arbitrary-sized chunks should encode to the same base64 text as encoding it
+// all at once.
+#[test]
+fn streaming_write_matches_bulk_encode() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..1_000 {
+        let size = rng.gen_range(0, 1000);
+        let mut bytes = vec![0u8; size];
+        rng.fill_bytes(&mut bytes);
+
+        let mut bulk_encoded = String::new();
+        encode_engine_string(&bytes[..], &mut bulk_encoded, &DEFAULT_ENGINE);
+
+        let mut encoded = Vec::new();
+        {
+            let mut writer = EncoderWriter::from(&mut encoded, &DEFAULT_ENGINE);
+            // write in small, uneven chunks to exercise buffering across `write` calls
+            for chunk in bytes.chunks(2) {
+                writer.write_all(chunk).unwrap();
+            }
+            writer.finish().unwrap();
+        }
+
+        assert_eq!(bulk_encoded.into_bytes(), encoded);
+    }
+}
+
+// `finish` must flush a final partial chunk even when it was never followed by more data.
+#[test]
+fn finish_flushes_a_trailing_partial_chunk() {
+    let mut encoded = Vec::new();
+    let mut writer = EncoderWriter::from(&mut encoded, &DEFAULT_ENGINE);
+    writer.write_all(b"01234").unwrap();
+    writer.finish().unwrap();
+
+    assert_eq!(b"MDEyMzQ=".to_vec(), encoded);
+}
+
+// With nothing ever written, `finish` should produce no output at all.
+#[test]
+fn finish_with_no_writes_produces_nothing() {
+    let mut encoded = Vec::new();
+    let mut writer = EncoderWriter::from(&mut encoded, &DEFAULT_ENGINE);
+    writer.finish().unwrap();
+
+    assert!(encoded.is_empty());
+}
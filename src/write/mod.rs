@@ -0,0 +1,10 @@
+//! Implementations of `std::io::Write` to transparently encode or decode base64.
+mod decoder;
+#[cfg(test)]
+mod decoder_tests;
+mod encoder;
+#[cfg(test)]
+mod encoder_tests;
+
+pub use self::decoder::DecoderWriter;
+pub use self::encoder::EncoderWriter;